@@ -6,7 +6,7 @@ use solana_sdk::signature::{Keypair, Signer};
 use solana_client::rpc_client::RpcClient;
 
 #[allow(dead_code)]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct Config {
     pub environment: Environment,
     pub network: Network,
@@ -15,35 +15,59 @@ pub struct Config {
     pub wallet: WalletConfig,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct Environment {
     #[serde(rename = "type")]
     pub env_type: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct Network {
     pub rpc_url: String,
     pub ws_url: String,
+    /// Commitment level ("processed", "confirmed", "finalized") used for all
+    /// RPC reads; defaults to "confirmed" when omitted from the config file.
+    #[serde(default = "default_commitment")]
+    pub commitment: String,
 }
 
-#[derive(Debug, Deserialize)]
+fn default_commitment() -> String {
+    "confirmed".to_string()
+}
+
+#[derive(Debug, Clone, Deserialize)]
 pub struct Programs {
     pub main_program: String,
     pub pool_contract: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct Execution {
     pub purchase_amount: u64,
     pub jito_tip: u64,
     pub slippage_percentage: f64,
+    /// Compute-unit priority fee, in micro-lamports per compute unit, passed
+    /// to `ComputeBudgetInstruction::set_compute_unit_price`. Independent of
+    /// `jito_tip`, which is a flat lamport transfer to the Jito tip account.
+    /// Defaults to `0` (no added priority fee) when omitted from the config
+    /// file, matching the other fields added alongside it.
+    #[serde(default)]
+    pub compute_unit_price_micro_lamports: u64,
+    /// Jito block-engine bundle endpoint (e.g. "https://ny.mainnet.block-engine.jito.wtf").
+    /// When absent, buys are submitted as plain RPC transactions instead of bundles.
+    #[serde(default)]
+    pub jito_block_engine_url: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct WalletConfig {
     pub keypair_path: String,
     pub min_sol_balance: u64,
+    /// Opt-in: when `environment.type` is "development" and the wallet is
+    /// below `min_sol_balance`, request and confirm a devnet airdrop instead
+    /// of refusing to start. Ignored outside development.
+    #[serde(default)]
+    pub request_airdrop: bool,
 }
 
 impl Config {
@@ -54,6 +78,48 @@ impl Config {
     }
 }
 
+/// Builders for a fully-populated `Config` shared by other modules' test
+/// harnesses (executor/BanksClient tests, criteria threshold tests), so
+/// those tests don't each hand-roll the same struct literal.
+#[cfg(test)]
+pub mod test_helpers {
+    use super::*;
+    use solana_sdk::pubkey::Pubkey;
+
+    pub fn test_config() -> Config {
+        test_config_with_program(Pubkey::new_unique())
+    }
+
+    pub fn test_config_with_program(program_id: Pubkey) -> Config {
+        Config {
+            environment: Environment {
+                env_type: "development".to_string(),
+            },
+            network: Network {
+                rpc_url: "http://localhost:8899".to_string(),
+                ws_url: "ws://localhost:8900".to_string(),
+                commitment: "confirmed".to_string(),
+            },
+            programs: Programs {
+                main_program: program_id.to_string(),
+                pool_contract: program_id.to_string(),
+            },
+            execution: Execution {
+                purchase_amount: 1_000_000,
+                jito_tip: 10_000,
+                slippage_percentage: 1.0,
+                compute_unit_price_micro_lamports: 1_000,
+                jito_block_engine_url: None,
+            },
+            wallet: WalletConfig {
+                keypair_path: "dev_wallet.json".to_string(),
+                min_sol_balance: 0,
+                request_airdrop: false,
+            },
+        }
+    }
+}
+
 pub struct PoolMonitor {
     config: Config,
     wallet: Keypair,
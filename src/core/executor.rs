@@ -0,0 +1,268 @@
+use anyhow::{anyhow, Context, Result};
+use rust_decimal::Decimal;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    bs58,
+    compute_budget::ComputeBudgetInstruction,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    system_instruction,
+    transaction::Transaction,
+};
+use std::str::FromStr;
+use tracing::info;
+
+use crate::config::Config;
+
+use super::pricing::Rate;
+use super::websocket::{PoolCreationEvent, PoolType};
+
+/// One of Jito's official mainnet-beta tip payment accounts.
+const JITO_TIP_ACCOUNT: &str = "96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5";
+
+/// Compute unit ceiling for a buy transaction (compute-budget ixs + swap + tip).
+const BUY_COMPUTE_UNIT_LIMIT: u32 = 200_000;
+
+/// Anchor instruction discriminator for the pump.fun/dao.fun "buy" instruction.
+pub(crate) const BUY_DISCRIMINATOR: [u8; 8] = [102, 6, 61, 18, 1, 218, 235, 234];
+
+/// Builds and submits buy transactions for detected pool creations, bidding a
+/// priority fee via compute-budget instructions and tipping a Jito validator
+/// so the bundle lands ahead of competing snipers.
+pub struct Executor {
+    rpc_client: RpcClient,
+    jito_block_engine_url: Option<String>,
+}
+
+impl Executor {
+    pub fn new(rpc_url: &str, jito_block_engine_url: Option<String>) -> Self {
+        Self {
+            rpc_client: RpcClient::new(rpc_url),
+            jito_block_engine_url,
+        }
+    }
+
+    /// Builds, signs, and submits a buy transaction for `event`, spending
+    /// `amount_lamports` and tipping `config.execution.jito_tip` lamports to
+    /// a Jito tip account. Submits via the Jito block-engine bundle endpoint
+    /// when configured, otherwise falls back to a plain RPC
+    /// `send_and_confirm_transaction`, which waits for confirmation.
+    pub async fn execute_buy(
+        &self,
+        wallet: &Keypair,
+        config: &Config,
+        event: &PoolCreationEvent,
+        amount_lamports: u64,
+    ) -> Result<Signature> {
+        let instructions = self.build_instructions(wallet, config, event, amount_lamports)?;
+
+        let recent_blockhash = self
+            .rpc_client
+            .get_latest_blockhash()
+            .context("failed to fetch recent blockhash")?;
+
+        let transaction = Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&wallet.pubkey()),
+            &[wallet],
+            recent_blockhash,
+        );
+
+        match &self.jito_block_engine_url {
+            Some(url) => self.send_bundle(url, &transaction).await,
+            None => {
+                let signature = self
+                    .rpc_client
+                    .send_and_confirm_transaction(&transaction)
+                    .context("failed to submit buy transaction")?;
+                info!("Buy transaction confirmed: {}", signature);
+                Ok(signature)
+            }
+        }
+    }
+
+    /// Assembles the full instruction list for a buy, in submission order:
+    /// compute-unit limit, compute-unit priority fee, the buy itself, then
+    /// the Jito tip transfer. Exposed so tests can drive the exact
+    /// instruction sequence `execute_buy` submits, rather than a copy of it.
+    pub(crate) fn build_instructions(
+        &self,
+        wallet: &Keypair,
+        config: &Config,
+        event: &PoolCreationEvent,
+        amount_lamports: u64,
+    ) -> Result<Vec<Instruction>> {
+        let tip_account =
+            Pubkey::from_str(JITO_TIP_ACCOUNT).context("invalid Jito tip account")?;
+
+        Ok(vec![
+            ComputeBudgetInstruction::set_compute_unit_limit(BUY_COMPUTE_UNIT_LIMIT),
+            ComputeBudgetInstruction::set_compute_unit_price(config.execution.compute_unit_price_micro_lamports),
+            self.build_buy_instruction(config, wallet, event, amount_lamports)?,
+            system_instruction::transfer(&wallet.pubkey(), &tip_account, config.execution.jito_tip),
+        ])
+    }
+
+    fn build_buy_instruction(
+        &self,
+        config: &Config,
+        wallet: &Keypair,
+        event: &PoolCreationEvent,
+        amount_lamports: u64,
+    ) -> Result<Instruction> {
+        let program_id = match event.pool_type {
+            PoolType::PumpFun => Pubkey::from_str(&config.programs.main_program),
+            PoolType::DaoFun => Pubkey::from_str(&config.programs.pool_contract),
+        }
+        .context("invalid program id in config")?;
+
+        let rate = Rate::from_reserves(event.base_reserve, event.quote_reserve)
+            .context("failed to derive pool rate from reserves")?;
+        let slippage = Decimal::try_from(config.execution.slippage_percentage)
+            .context("invalid slippage percentage")?;
+        let min_out = rate
+            .min_output(amount_lamports, slippage)
+            .context("failed to compute slippage-protected minimum output")?;
+
+        let mut data = Vec::with_capacity(BUY_DISCRIMINATOR.len() + 16);
+        data.extend_from_slice(&BUY_DISCRIMINATOR);
+        data.extend_from_slice(&amount_lamports.to_le_bytes());
+        data.extend_from_slice(&min_out.to_le_bytes());
+
+        Ok(Instruction::new_with_bytes(
+            program_id,
+            &data,
+            vec![
+                AccountMeta::new(wallet.pubkey(), true),
+                AccountMeta::new(event.pool_address, false),
+                AccountMeta::new_readonly(event.token_address, false),
+            ],
+        ))
+    }
+
+    async fn send_bundle(&self, block_engine_url: &str, transaction: &Transaction) -> Result<Signature> {
+        let serialized = bincode::serialize(transaction).context("failed to serialize transaction")?;
+        let encoded = bs58::encode(serialized).into_string();
+
+        let client = reqwest::Client::new();
+        let response = client
+            .post(format!("{}/api/v1/bundles", block_engine_url))
+            .json(&serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "sendBundle",
+                "params": [[encoded]],
+            }))
+            .send()
+            .await
+            .context("failed to reach Jito block engine")?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "Jito bundle submission failed with status {}",
+                response.status()
+            ));
+        }
+
+        let signature = transaction
+            .signatures
+            .first()
+            .copied()
+            .ok_or_else(|| anyhow!("signed transaction is missing its signature"))?;
+
+        info!("Submitted Jito bundle, leading signature: {}", signature);
+        Ok(signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::test_helpers::test_config_with_program;
+    use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError};
+    use solana_program_test::{processor, ProgramTest};
+
+    /// Stub pump.fun/dao.fun program: accepts any instruction whose data
+    /// starts with our `BUY_DISCRIMINATOR`, rejecting everything else.
+    fn process_stub_buy(
+        _program_id: &Pubkey,
+        _accounts: &[AccountInfo],
+        data: &[u8],
+    ) -> ProgramResult {
+        if data.starts_with(&BUY_DISCRIMINATOR) {
+            Ok(())
+        } else {
+            Err(ProgramError::InvalidInstructionData)
+        }
+    }
+
+    #[tokio::test]
+    async fn buy_transaction_orders_compute_budget_buy_then_tip_and_submits() {
+        let stub_program_id = Pubkey::new_unique();
+        let mut program_test = ProgramTest::new(
+            "stub_pool_program",
+            stub_program_id,
+            processor!(process_stub_buy),
+        );
+        // The Jito tip account is a funded, rent-exempt account on mainnet;
+        // mirror that so the small tip transfer isn't rejected for rent.
+        program_test.add_account(
+            Pubkey::from_str(JITO_TIP_ACCOUNT).unwrap(),
+            solana_sdk::account::Account {
+                lamports: 1_000_000_000,
+                ..Default::default()
+            },
+        );
+        let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+        let config = test_config_with_program(stub_program_id);
+        let executor = Executor::new("http://localhost:8899", None);
+        let event = PoolCreationEvent {
+            signature: "stub".to_string(),
+            pool_address: Pubkey::new_unique(),
+            token_address: Pubkey::new_unique(),
+            holder_count: 140,
+            buy_count: 140,
+            base_reserve: 1_000_000,
+            quote_reserve: 1_000,
+            timestamp: 0,
+            slot: 0,
+            pool_type: PoolType::PumpFun,
+        };
+
+        let instructions = executor
+            .build_instructions(&payer, &config, &event, config.execution.purchase_amount)
+            .unwrap();
+
+        assert_eq!(instructions.len(), 4, "compute limit, compute price, buy, tip");
+        assert_eq!(instructions[2].program_id, stub_program_id);
+        assert!(instructions[2].data.starts_with(&BUY_DISCRIMINATOR));
+
+        let data = &instructions[2].data;
+        let encoded_amount = u64::from_le_bytes(data[8..16].try_into().unwrap());
+        let encoded_min_out = u64::from_le_bytes(data[16..24].try_into().unwrap());
+        assert_eq!(encoded_amount, config.execution.purchase_amount);
+
+        let expected_rate = Rate::from_reserves(event.base_reserve, event.quote_reserve).unwrap();
+        let expected_min_out = expected_rate
+            .min_output(
+                config.execution.purchase_amount,
+                Decimal::try_from(config.execution.slippage_percentage).unwrap(),
+            )
+            .unwrap();
+        assert_eq!(encoded_min_out, expected_min_out);
+
+        let transaction = Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&payer.pubkey()),
+            &[&payer],
+            recent_blockhash,
+        );
+
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("compute-budget + buy + tip transaction should land");
+    }
+}
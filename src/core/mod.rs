@@ -3,23 +3,28 @@ use solana_sdk::signature::{Keypair, Signer};
 use solana_sdk::pubkey::Pubkey;
 use solana_client::rpc_client::RpcClient;
 use anyhow::Result;
+use std::sync::Arc;
 use tracing::{info, error, warn};
 
+mod executor;
+mod pricing;
 mod websocket;
+pub use executor::Executor;
+pub use pricing::{Amount, Rate};
 pub use websocket::{WebsocketMonitor, PoolType};
 
 pub struct PoolMonitor {
     config: Config,
     wallet: Keypair,
     rpc_client: RpcClient,
-    websocket_monitor: WebsocketMonitor,
+    websocket_monitor: Arc<WebsocketMonitor>,
 }
 
 impl PoolMonitor {
     pub fn new(config: Config, wallet: Keypair) -> Result<Self> {
         let rpc_client = RpcClient::new(&config.network.rpc_url);
-        let websocket_monitor = WebsocketMonitor::new(&config.network.ws_url, &config)?;
-        
+        let websocket_monitor = Arc::new(WebsocketMonitor::new(&config.network.ws_url, &config, &wallet)?);
+
         Ok(Self {
             config,
             wallet,
@@ -30,22 +35,42 @@ impl PoolMonitor {
 
     pub async fn start(&self) -> Result<()> {
         info!("Starting pool monitor...");
-        
+
         // Verify wallet balance
-        let balance = self.rpc_client.get_balance(&self.wallet.pubkey())?;
+        let mut balance = self.rpc_client.get_balance(&self.wallet.pubkey())?;
         info!("Wallet balance: {} SOL", balance as f64 / 1_000_000_000.0);
 
         if balance < self.config.wallet.min_sol_balance {
-            error!("Insufficient balance for trading");
-            return Ok(());
+            if self.config.environment.env_type == "development" && self.config.wallet.request_airdrop {
+                balance = self.request_devnet_airdrop(balance)?;
+            }
+
+            if balance < self.config.wallet.min_sol_balance {
+                error!("Insufficient balance for trading");
+                return Ok(());
+            }
         }
 
         // Start monitoring
         self.monitor_pools().await
     }
 
+    /// Requests and confirms a devnet airdrop to cover the shortfall between
+    /// `balance` and `config.wallet.min_sol_balance`, returning the new balance.
+    fn request_devnet_airdrop(&self, balance: u64) -> Result<u64> {
+        let shortfall = self.config.wallet.min_sol_balance - balance;
+        warn!("Wallet balance below minimum; requesting a devnet airdrop of {} lamports", shortfall);
+
+        let signature = self.rpc_client.request_airdrop(&self.wallet.pubkey(), shortfall)?;
+        self.rpc_client.poll_for_signature(&signature)?;
+
+        let new_balance = self.rpc_client.get_balance(&self.wallet.pubkey())?;
+        info!("Airdrop confirmed; new balance: {} SOL", new_balance as f64 / 1_000_000_000.0);
+        Ok(new_balance)
+    }
+
     async fn monitor_pools(&self) -> Result<()> {
         info!("Monitoring pools for {} program", self.config.programs.main_program);
-        self.websocket_monitor.subscribe_to_logs().await
+        Arc::clone(&self.websocket_monitor).subscribe_to_logs().await
     }
 }
\ No newline at end of file
@@ -0,0 +1,90 @@
+use anyhow::{anyhow, Result};
+use rust_decimal::prelude::*;
+
+/// Lamports (or smallest token unit) - a plain `u64` quantity moved by a swap.
+pub type Amount = u64;
+
+/// How many base-token units a single quote-token unit (e.g. one lamport)
+/// buys in a constant-product pool, used to derive a slippage-protected
+/// minimum output: `output = input_in_quote_units * rate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rate(Decimal);
+
+impl Rate {
+    /// Derives the pool's current base-per-quote price from its
+    /// constant-product reserves.
+    pub fn from_reserves(base_reserve: u64, quote_reserve: u64) -> Result<Self> {
+        let base = Decimal::from(base_reserve);
+        let quote = Decimal::from(quote_reserve);
+
+        let rate = base
+            .checked_div(quote)
+            .ok_or_else(|| anyhow!("rate overflow or zero quote reserve"))?;
+
+        if rate.is_zero() {
+            return Err(anyhow!("pool rate is zero"));
+        }
+
+        Ok(Self(rate))
+    }
+
+    /// Computes the minimum acceptable output for `input`, after applying
+    /// `slippage` (a percentage, e.g. `1.5` for 1.5%), so a swap instruction
+    /// can revert on-chain rather than filling at an unexpectedly bad price.
+    pub fn min_output(&self, input: Amount, slippage: Decimal) -> Result<Amount> {
+        if self.0.is_zero() {
+            return Err(anyhow!("cannot compute output against a zero rate"));
+        }
+
+        let gross_output = Decimal::from(input)
+            .checked_mul(self.0)
+            .ok_or_else(|| anyhow!("gross output overflow"))?;
+
+        let slippage_factor = Decimal::ONE
+            .checked_sub(
+                slippage
+                    .checked_div(Decimal::from(100))
+                    .ok_or_else(|| anyhow!("slippage overflow"))?,
+            )
+            .ok_or_else(|| anyhow!("slippage factor underflow"))?;
+
+        let min_output = gross_output
+            .checked_mul(slippage_factor)
+            .ok_or_else(|| anyhow!("minimum output overflow"))?;
+
+        min_output
+            .to_u64()
+            .ok_or_else(|| anyhow!("minimum output does not fit in a u64 amount"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_output_scales_with_the_pool_price_not_against_it() {
+        // A realistic pump.fun-style bonding curve: a huge token reserve
+        // against a small lamport reserve, so base/quote is itself huge.
+        let rate = Rate::from_reserves(1_000_000_000_000, 1_000).unwrap();
+
+        // 10 lamports in, with no slippage tolerance, should buy roughly
+        // 10 * rate tokens - not a number crushed back down near zero by
+        // dividing against a multi-billion-to-one rate.
+        let min_out = rate.min_output(10, Decimal::ZERO).unwrap();
+        assert_eq!(min_out, 10_000_000_000);
+    }
+
+    #[test]
+    fn min_output_applies_slippage_as_a_percentage_discount() {
+        let rate = Rate::from_reserves(200, 100).unwrap();
+
+        let min_out = rate.min_output(10, Decimal::from(50)).unwrap();
+        assert_eq!(min_out, 10); // 10 * 2.0 rate = 20 gross, minus 50% slippage = 10
+    }
+
+    #[test]
+    fn from_reserves_rejects_a_zero_quote_reserve() {
+        assert!(Rate::from_reserves(1_000, 0).is_err());
+    }
+}
@@ -1,15 +1,56 @@
 use {
-    anyhow::{Result, anyhow},
-    futures::{SinkExt, StreamExt},
-    serde_json::{json, Value},
-    std::collections::HashMap,
-    tokio_tungstenite::{connect_async, WebSocketStream},
+    anyhow::{Context, Result, anyhow},
+    rand::Rng,
+    solana_account_decoder::UiAccountEncoding,
+    solana_client::{
+        rpc_client::RpcClient,
+        rpc_config::{
+            GetConfirmedSignaturesForAddress2Config, RpcAccountInfoConfig,
+            RpcProgramAccountsConfig, RpcTransactionConfig, RpcTransactionLogsConfig,
+            RpcTransactionLogsFilter,
+        },
+        rpc_filter::{Memcmp, RpcFilterType},
+        rpc_response::{Response as RpcResponse, RpcLogsResponse},
+    },
+    solana_pubsub_client::pubsub_client::PubsubClient,
+    solana_transaction_status::{
+        EncodedTransaction, ParsedAccount, UiInstruction, UiMessage, UiParsedInstruction,
+        UiTransactionEncoding, UiTransactionStatusMeta,
+    },
+    std::{collections::HashMap, str::FromStr, sync::{Arc, Mutex}, time::Duration},
+    tokio::time::sleep,
     tracing::{info, error, warn, debug},
     chrono::Utc,
-    solana_sdk::pubkey::Pubkey,
-    crate::config::{Config, Environment},
+    solana_sdk::{
+        bs58,
+        commitment_config::{CommitmentConfig, CommitmentLevel},
+        pubkey::Pubkey,
+        signature::{Keypair, Signature},
+    },
+    crate::config::Config,
+    super::executor::{Executor, BUY_DISCRIMINATOR},
 };
 
+/// SPL Token program id; token accounts are 165-byte structs laid out as
+/// mint@0..32, owner@32..64, amount@64..72 (little-endian u64).
+const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+const TOKEN_ACCOUNT_LEN: usize = 165;
+
+const PUMP_FUN_PROGRAM: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
+const DAO_FUN_PROGRAM: &str = "5jnapfrAN47UYkLkEf7HnprPPBCQLvkYWGZDeKkaP5hv";
+
+/// SPL Associated Token Account program; owns the `CreateIdempotent`
+/// instruction new pools use to create their mint's first token account.
+const ASSOCIATED_TOKEN_PROGRAM_ID: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+
+const RECONNECT_BASE_DELAY: Duration = Duration::from_millis(250);
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// How many times `extract_pool_creation_event` retries a transaction that
+/// isn't yet visible before giving up and dropping the pool creation.
+const EXTRACTION_MAX_ATTEMPTS: usize = 5;
+const EXTRACTION_RETRY_DELAY: Duration = Duration::from_millis(500);
+
 #[derive(Debug, Clone)]
 pub enum PoolType {
     PumpFun,  // Development
@@ -23,6 +64,11 @@ pub struct PoolCreationEvent {
     pub token_address: Pubkey,
     pub holder_count: u64,
     pub buy_count: u64,
+    /// Constant-product base/quote reserves read from the creation
+    /// transaction's post-balances, used to derive a `Rate` for
+    /// slippage-protected swaps.
+    pub base_reserve: u64,
+    pub quote_reserve: u64,
     pub timestamp: i64,
     pub slot: u64,
     pub pool_type: PoolType,
@@ -52,104 +98,165 @@ impl Default for PumpFunCriteria {
 pub struct WebsocketMonitor {
     ws_url: String,
     config: Config,
-    token_metrics: HashMap<String, PumpFunCriteria>,
+    wallet: Keypair,
+    executor: Executor,
+    rpc_client: RpcClient,
+    /// Holder counts cached per mint.
+    holder_cache: Mutex<HashMap<String, u64>>,
+    /// Buy counts cached per pool.
+    buy_cache: Mutex<HashMap<String, u64>>,
 }
 
 impl WebsocketMonitor {
-    pub fn new(ws_url: &str, config: &Config) -> Result<Self> {
+    pub fn new(ws_url: &str, config: &Config, wallet: &Keypair) -> Result<Self> {
+        let executor = Executor::new(
+            &config.network.rpc_url,
+            config.execution.jito_block_engine_url.clone(),
+        );
+        let rpc_client = RpcClient::new(&config.network.rpc_url);
+
         Ok(Self {
             ws_url: ws_url.to_string(),
             config: config.clone(),
-            token_metrics: HashMap::new(),
+            wallet: wallet.insecure_clone(),
+            executor,
+            rpc_client,
+            holder_cache: Mutex::new(HashMap::new()),
+            buy_cache: Mutex::new(HashMap::new()),
         })
     }
 
-    pub async fn subscribe_to_logs(&self) -> Result<()> {
-        info!("Connecting to websocket...");
-        
-        let (ws_stream, _) = connect_async(&self.ws_url).await?;
-        info!("WebSocket connected successfully");
-
-        let subscribe_msg = json!({
-            "jsonrpc": "2.0",
-            "id": 1,
-            "method": "logsSubscribe",
-            "params": [
-                {
-                    "mentions": [
-                        "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA", // pump.fun
-                        "5jnapfrAN47UYkLkEf7HnprPPBCQLvkYWGZDeKkaP5hv", // dao.fun
-                        "CreateIdempotent"
-                    ]
-                },
-                {"commitment": "processed"}
-            ]
-        });
+    fn commitment_config(&self) -> CommitmentConfig {
+        CommitmentConfig {
+            commitment: match self.config.network.commitment.as_str() {
+                "processed" => CommitmentLevel::Processed,
+                "finalized" => CommitmentLevel::Finalized,
+                _ => CommitmentLevel::Confirmed,
+            },
+        }
+    }
 
-        self.process_logs(ws_stream, subscribe_msg).await
+    /// Runs independent log subscriptions for pump.fun and dao.fun
+    /// concurrently — `logsSubscribe`'s `mentions` filter only accepts a
+    /// single address per subscription, so each program gets its own
+    /// connection and its own supervisor/backoff state.
+    pub async fn subscribe_to_logs(self: Arc<Self>) -> Result<()> {
+        tokio::try_join!(
+            Arc::clone(&self).supervise_mention(PUMP_FUN_PROGRAM.to_string()),
+            self.supervise_mention(DAO_FUN_PROGRAM.to_string()),
+        )?;
+        Ok(())
     }
 
-    async fn process_logs(&self, mut ws_stream: WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>, subscribe_msg: Value) -> Result<()> {
-        ws_stream.send(tokio_tungstenite::tungstenite::Message::Text(subscribe_msg.to_string())).await?;
-        
-        while let Some(msg) = ws_stream.next().await {
-            match msg {
-                Ok(msg) => {
-                    if let Ok(log_data) = serde_json::from_str::<Value>(&msg.to_string()) {
-                        if let Some(pool_type) = self.is_create_idempotent(&log_data) {
-                            match (pool_type, &self.config.environment.environment) {
-                                (PoolType::PumpFun, Environment::Development) => {
-                                    info!("Detected pump.fun pool creation in development");
-                                    self.handle_pump_fun_creation(&log_data).await?;
-                                },
-                                (PoolType::DaoFun, Environment::Production) => {
-                                    info!("Detected dao.fun pool creation in production");
-                                    self.handle_dao_fun_creation(&log_data).await?;
-                                },
-                                _ => {
-                                    debug!("Ignoring pool creation - environment mismatch");
-                                }
-                            }
-                        }
+    /// Runs a single program's log subscription under a supervisor that
+    /// reconnects on any disconnect with exponential backoff (250ms doubling
+    /// to a 30s cap, reset once a connection has delivered at least one
+    /// notification, whether or not handling it succeeded) so a
+    /// dropped websocket never takes down `PoolMonitor::start`.
+    async fn supervise_mention(self: Arc<Self>, mention: String) -> Result<()> {
+        let mut backoff = RECONNECT_BASE_DELAY;
+
+        loop {
+            info!("Connecting to websocket for {}...", mention);
+
+            match Arc::clone(&self).run_subscription(mention.clone()).await {
+                Ok(received_any) => {
+                    if received_any {
+                        info!("Log stream recovered for {}", mention);
+                        backoff = RECONNECT_BASE_DELAY;
                     }
+                    warn!("Log subscription stream ended for {}, reconnecting...", mention);
                 }
                 Err(e) => {
-                    error!("WebSocket message error: {:?}", e);
-                    return Err(anyhow!("WebSocket error: {:?}", e));
+                    warn!("Log subscription dropped for {} ({}), reconnecting...", mention, e);
+                }
+            }
+
+            let jitter = Duration::from_millis(rand::thread_rng().gen_range(0..100));
+            sleep(backoff + jitter).await;
+            backoff = (backoff * 2).min(RECONNECT_MAX_DELAY);
+        }
+    }
+
+    /// Opens a single `PubsubClient` logs subscription for one program
+    /// address and drives it to completion. Returns whether at least one
+    /// notification was processed, which the caller uses to decide whether
+    /// to reset the backoff.
+    async fn run_subscription(self: Arc<Self>, mention: String) -> Result<bool> {
+        let ws_url = self.ws_url.clone();
+        let commitment = self.commitment_config();
+        let handle = tokio::runtime::Handle::current();
+        let monitor = self;
+
+        tokio::task::spawn_blocking(move || -> Result<bool> {
+            let (subscription, receiver) = PubsubClient::logs_subscribe(
+                &ws_url,
+                RpcTransactionLogsFilter::Mentions(vec![mention]),
+                RpcTransactionLogsConfig {
+                    commitment: Some(commitment),
+                },
+            )
+            .map_err(|e| anyhow!("failed to subscribe to logs: {:?}", e))?;
+
+            info!("WebSocket connected successfully");
+
+            let mut received_any = false;
+            for response in receiver.iter() {
+                received_any = true;
+                if let Err(e) = handle.block_on(monitor.handle_logs_response(response)) {
+                    error!("Error handling log notification: {:?}", e);
+                }
+            }
+
+            let _ = subscription.shutdown();
+            Ok(received_any)
+        })
+        .await
+        .map_err(|e| anyhow!("subscription task panicked: {:?}", e))?
+    }
+
+    async fn handle_logs_response(&self, response: RpcResponse<RpcLogsResponse>) -> Result<()> {
+        let slot = response.context.slot;
+        let value = response.value;
+
+        if let Some(pool_type) = self.classify_logs(&value.logs) {
+            match (pool_type, self.config.environment.env_type.as_str()) {
+                (PoolType::PumpFun, "development") => {
+                    info!("Detected pump.fun pool creation in development");
+                    self.handle_pump_fun_creation(&value.signature, slot).await?;
+                },
+                (PoolType::DaoFun, "production") => {
+                    info!("Detected dao.fun pool creation in production");
+                    self.handle_dao_fun_creation(&value.signature, slot).await?;
+                },
+                _ => {
+                    debug!("Ignoring pool creation - environment mismatch");
                 }
             }
         }
         Ok(())
     }
 
-    fn is_create_idempotent(&self, log_data: &Value) -> Option<PoolType> {
-        if let Some(logs) = log_data.get("result").and_then(|r| r.get("logs")) {
-            logs.as_array().map_or(None, |log_array| {
-                for log in log_array {
-                    if let Some(log_str) = log.as_str() {
-                        if log_str.contains("CreateIdempotent") {
-                            if log_str.contains("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA") {
-                                return Some(PoolType::PumpFun);
-                            }
-                            if log_str.contains("5jnapfrAN47UYkLkEf7HnprPPBCQLvkYWGZDeKkaP5hv") {
-                                return Some(PoolType::DaoFun);
-                            }
-                        }
-                    }
+    fn classify_logs(&self, logs: &[String]) -> Option<PoolType> {
+        for log in logs {
+            if log.contains("CreateIdempotent") {
+                if log.contains(PUMP_FUN_PROGRAM) {
+                    return Some(PoolType::PumpFun);
                 }
-                None
-            })
-        } else {
-            None
+                if log.contains(DAO_FUN_PROGRAM) {
+                    return Some(PoolType::DaoFun);
+                }
+            }
         }
+        None
     }
 
-    async fn handle_pump_fun_creation(&self, log_data: &Value) -> Result<()> {
-        if let Some(event) = self.extract_pool_creation_event(&log_data, PoolType::PumpFun).await? {
-            let criteria = self.verify_pump_fun_criteria(&event.token_address).await?;
-            
+    async fn handle_pump_fun_creation(&self, signature: &str, slot: u64) -> Result<()> {
+        if let Some(event) = self.extract_pool_creation_event(signature, slot, PoolType::PumpFun).await? {
+            let criteria = self.verify_pump_fun_criteria(&event).await?;
+
             self.log_criteria_check(&event.token_address, &criteria);
-            
+
             if self.is_valid_pump_fun_criteria(&criteria) {
                 info!("Valid pump.fun pool creation detected");
                 self.handle_valid_pool_creation(event).await?;
@@ -158,17 +265,17 @@ impl WebsocketMonitor {
         Ok(())
     }
 
-    async fn handle_dao_fun_creation(&self, log_data: &Value) -> Result<()> {
-        if let Some(event) = self.extract_pool_creation_event(&log_data, PoolType::DaoFun).await? {
+    async fn handle_dao_fun_creation(&self, signature: &str, slot: u64) -> Result<()> {
+        if let Some(event) = self.extract_pool_creation_event(signature, slot, PoolType::DaoFun).await? {
             info!("Valid dao.fun pool creation detected: {}", event.token_address);
             self.handle_valid_pool_creation(event).await?;
         }
         Ok(())
     }
 
-    async fn verify_pump_fun_criteria(&self, token_address: &Pubkey) -> Result<PumpFunCriteria> {
-        let holder_count = self.get_holder_count(token_address).await?;
-        let buy_count = self.get_buy_count(token_address).await?;
+    async fn verify_pump_fun_criteria(&self, event: &PoolCreationEvent) -> Result<PumpFunCriteria> {
+        let holder_count = self.get_holder_count(&event.token_address).await?;
+        let buy_count = self.get_buy_count(&event.pool_address, event.timestamp).await?;
 
         Ok(PumpFunCriteria {
             holder_count,
@@ -214,49 +321,414 @@ impl WebsocketMonitor {
         }
     }
 
-    async fn extract_pool_creation_event(&self, log_data: &Value, pool_type: PoolType) -> Result<Option<PoolCreationEvent>> {
-        // Extract signature
-        let signature = log_data.get("result")
-            .and_then(|r| r.get("signature"))
-            .and_then(|s| s.as_str())
-            .ok_or_else(|| anyhow!("Missing signature"))?
-            .to_string();
+    /// Fetches and decodes the pool-creation event for `signature`, retrying
+    /// with backoff while the transaction isn't yet visible at the configured
+    /// commitment, escalating to `Finalized` on the final attempt before
+    /// giving up and returning `Ok(None)`.
+    async fn extract_pool_creation_event(&self, signature: &str, slot: u64, pool_type: PoolType) -> Result<Option<PoolCreationEvent>> {
+        for attempt in 1..=EXTRACTION_MAX_ATTEMPTS {
+            let commitment = if attempt == EXTRACTION_MAX_ATTEMPTS {
+                CommitmentConfig::finalized()
+            } else {
+                self.commitment_config()
+            };
 
-        // Extract other fields (simplified for example)
-        let pool_address = Pubkey::new_unique(); // TODO: Extract from logs
-        let token_address = Pubkey::new_unique(); // TODO: Extract from logs
-        let slot = log_data.get("result")
-            .and_then(|r| r.get("slot"))
-            .and_then(|s| s.as_u64())
-            .ok_or_else(|| anyhow!("Missing slot"))?;
+            match self.fetch_pool_creation_event(signature, slot, pool_type.clone(), commitment) {
+                Ok(event) => return Ok(event),
+                Err(e) if attempt < EXTRACTION_MAX_ATTEMPTS => {
+                    debug!(
+                        "Transaction {} not yet available ({}); retrying ({}/{})",
+                        signature, e, attempt, EXTRACTION_MAX_ATTEMPTS
+                    );
+                    sleep(EXTRACTION_RETRY_DELAY * attempt as u32).await;
+                }
+                Err(e) => {
+                    warn!(
+                        "Transaction {} still unavailable after {} attempts ({}); giving up",
+                        signature, EXTRACTION_MAX_ATTEMPTS, e
+                    );
+                    return Ok(None);
+                }
+            }
+        }
 
-        let timestamp = chrono::Utc::now().timestamp();
+        Ok(None)
+    }
+
+    fn fetch_pool_creation_event(
+        &self,
+        signature: &str,
+        slot: u64,
+        pool_type: PoolType,
+        commitment: CommitmentConfig,
+    ) -> Result<Option<PoolCreationEvent>> {
+        let sig = Signature::from_str(signature).context("invalid transaction signature")?;
+
+        let tx = self.rpc_client.get_transaction_with_config(
+            &sig,
+            RpcTransactionConfig {
+                encoding: Some(UiTransactionEncoding::JsonParsed),
+                commitment: Some(commitment),
+                max_supported_transaction_version: Some(0),
+            },
+        )?;
+
+        let meta = tx.transaction.meta.clone();
+
+        let EncodedTransaction::Json(ui_tx) = tx.transaction.transaction else {
+            return Ok(None);
+        };
+        let UiMessage::Parsed(message) = ui_tx.message else {
+            return Ok(None);
+        };
+
+        let Some((pool_address, token_address)) = Self::find_create_idempotent_accounts(&message.instructions) else {
+            debug!("No CreateIdempotent instruction found in transaction {}", signature);
+            return Ok(None);
+        };
+
+        let (base_reserve, quote_reserve) = Self::extract_reserves(
+            meta.as_ref(),
+            &message.account_keys,
+            &pool_address,
+            &token_address,
+        );
 
         Ok(Some(PoolCreationEvent {
-            signature,
+            signature: signature.to_string(),
             pool_address,
             token_address,
             holder_count: 0,
             buy_count: 0,
-            timestamp,
-            slot,
+            base_reserve,
+            quote_reserve,
+            timestamp: tx.block_time.unwrap_or_else(|| Utc::now().timestamp()),
+            slot: tx.slot,
             pool_type,
         }))
     }
 
-    async fn get_holder_count(&self, _token_address: &Pubkey) -> Result<u64> {
-        // TODO: Implement actual API call to get holder count
-        Ok(150) // Placeholder
+    /// Reads the pool's post-transaction reserves straight from the creation
+    /// transaction: `quote_reserve` is the pool account's lamport balance and
+    /// `base_reserve` is the token amount its associated token account holds
+    /// for `token_address`, both after the `CreateIdempotent` instruction ran.
+    fn extract_reserves(
+        meta: Option<&UiTransactionStatusMeta>,
+        account_keys: &[ParsedAccount],
+        pool_address: &Pubkey,
+        token_address: &Pubkey,
+    ) -> (u64, u64) {
+        let Some(meta) = meta else {
+            return (0, 0);
+        };
+
+        let pool_address_str = pool_address.to_string();
+        let quote_reserve = account_keys
+            .iter()
+            .position(|key| key.pubkey == pool_address_str)
+            .and_then(|index| meta.post_balances.get(index))
+            .copied()
+            .unwrap_or(0);
+
+        let post_token_balances: Option<Vec<_>> = meta.post_token_balances.clone().into();
+        let base_reserve = post_token_balances
+            .unwrap_or_default()
+            .into_iter()
+            .find(|balance| {
+                let owner: Option<String> = balance.owner.clone().into();
+                owner.as_deref() == Some(pool_address_str.as_str())
+                    && balance.mint == token_address.to_string()
+            })
+            .and_then(|balance| balance.ui_token_amount.amount.parse::<u64>().ok())
+            .unwrap_or(0);
+
+        (base_reserve, quote_reserve)
     }
 
-    async fn get_buy_count(&self, _token_address: &Pubkey) -> Result<u64> {
-        // TODO: Implement actual API call to get buy count
-        Ok(200) // Placeholder
+    /// Locates the `CreateIdempotent` instruction (resolving address-lookup-table
+    /// entries, which `JsonParsed` encoding already folds into each instruction's
+    /// account list) and returns its `(associated_token_account, mint)` pair.
+    fn find_create_idempotent_accounts(instructions: &[UiInstruction]) -> Option<(Pubkey, Pubkey)> {
+        for instruction in instructions {
+            match instruction {
+                UiInstruction::Parsed(UiParsedInstruction::Parsed(parsed)) => {
+                    if parsed.program_id != ASSOCIATED_TOKEN_PROGRAM_ID {
+                        continue;
+                    }
+                    if parsed.parsed.get("type").and_then(|t| t.as_str()) != Some("createIdempotent") {
+                        continue;
+                    }
+                    let info = parsed.parsed.get("info")?;
+                    let token_address = Pubkey::from_str(info.get("mint")?.as_str()?).ok()?;
+                    let pool_address = Pubkey::from_str(info.get("account")?.as_str()?).ok()?;
+                    return Some((pool_address, token_address));
+                }
+                UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(partial)) => {
+                    if partial.program_id != ASSOCIATED_TOKEN_PROGRAM_ID || partial.accounts.len() < 4 {
+                        continue;
+                    }
+                    // CreateIdempotent account order: [funding, associated_token_account, wallet, mint, ...]
+                    let pool_address = Pubkey::from_str(&partial.accounts[1]).ok()?;
+                    let token_address = Pubkey::from_str(&partial.accounts[3]).ok()?;
+                    return Some((pool_address, token_address));
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    async fn get_holder_count(&self, token_address: &Pubkey) -> Result<u64> {
+        let mint_key = token_address.to_string();
+        if let Some(&cached) = self.holder_cache.lock().unwrap().get(&mint_key) {
+            if cached > 0 {
+                return Ok(cached);
+            }
+        }
+
+        let token_program = Pubkey::from_str(TOKEN_PROGRAM_ID)?;
+        let filters = vec![
+            RpcFilterType::DataSize(TOKEN_ACCOUNT_LEN as u64),
+            RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, &token_address.to_bytes())),
+        ];
+
+        let accounts = self.rpc_client.get_program_accounts_with_config(
+            &token_program,
+            RpcProgramAccountsConfig {
+                filters: Some(filters),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    commitment: Some(self.commitment_config()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )?;
+
+        let holder_count = accounts
+            .iter()
+            .filter(|(_, account)| {
+                account
+                    .data
+                    .get(64..72)
+                    .map(|amount| u64::from_le_bytes(amount.try_into().unwrap()) > 0)
+                    .unwrap_or(false)
+            })
+            .count() as u64;
+
+        self.holder_cache.lock().unwrap().insert(mint_key, holder_count);
+
+        Ok(holder_count)
+    }
+
+    async fn get_buy_count(&self, pool_address: &Pubkey, since_timestamp: i64) -> Result<u64> {
+        let pool_key = pool_address.to_string();
+        if let Some(&cached) = self.buy_cache.lock().unwrap().get(&pool_key) {
+            if cached > 0 {
+                return Ok(cached);
+            }
+        }
+
+        let commitment = self.commitment_config();
+        let mut buy_count = 0u64;
+        let mut before: Option<Signature> = None;
+
+        loop {
+            let signatures = self.rpc_client.get_signatures_for_address_with_config(
+                pool_address,
+                GetConfirmedSignaturesForAddress2Config {
+                    before,
+                    until: None,
+                    limit: Some(1000),
+                    commitment: Some(commitment),
+                },
+            )?;
+
+            if signatures.is_empty() {
+                break;
+            }
+
+            let mut reached_creation = false;
+            for entry in &signatures {
+                if entry.block_time.map_or(false, |t| t < since_timestamp) {
+                    reached_creation = true;
+                    break;
+                }
+                let signature = Signature::from_str(&entry.signature)?;
+                if self.is_buy_transaction(&signature).await? {
+                    buy_count += 1;
+                }
+            }
+
+            before = signatures
+                .last()
+                .map(|entry| Signature::from_str(&entry.signature))
+                .transpose()?;
+
+            if reached_creation || signatures.len() < 1000 {
+                break;
+            }
+        }
+
+        self.buy_cache.lock().unwrap().insert(pool_key, buy_count);
+
+        Ok(buy_count)
+    }
+
+    async fn is_buy_transaction(&self, signature: &Signature) -> Result<bool> {
+        let tx = self.rpc_client.get_transaction_with_config(
+            signature,
+            RpcTransactionConfig {
+                encoding: Some(UiTransactionEncoding::JsonParsed),
+                commitment: Some(self.commitment_config()),
+                max_supported_transaction_version: Some(0),
+            },
+        )?;
+
+        let EncodedTransaction::Json(ui_tx) = tx.transaction.transaction else {
+            return Ok(false);
+        };
+        let UiMessage::Parsed(message) = ui_tx.message else {
+            return Ok(false);
+        };
+
+        for instruction in message.instructions {
+            let UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(partial)) = instruction else {
+                continue;
+            };
+            let is_pool_program = partial.program_id == self.config.programs.main_program
+                || partial.program_id == self.config.programs.pool_contract;
+            if !is_pool_program {
+                continue;
+            }
+            if let Ok(data) = bs58::decode(&partial.data).into_vec() {
+                if data.starts_with(&BUY_DISCRIMINATOR) {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
     }
 
     async fn handle_valid_pool_creation(&self, event: PoolCreationEvent) -> Result<()> {
         info!("Processing valid pool creation: {:?}", event);
-        // TODO: Implement transaction execution
+
+        let signature = self
+            .executor
+            .execute_buy(
+                &self.wallet,
+                &self.config,
+                &event,
+                self.config.execution.purchase_amount,
+            )
+            .await?;
+
+        info!(
+            "Submitted buy for token {} (signature: {})",
+            event.token_address, signature
+        );
         Ok(())
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn criteria(holder_count: u64, buy_count: u64) -> PumpFunCriteria {
+        PumpFunCriteria {
+            holder_count,
+            buy_count,
+            ..Default::default()
+        }
+    }
+
+    fn monitor() -> WebsocketMonitor {
+        WebsocketMonitor {
+            ws_url: "ws://localhost:8900".to_string(),
+            config: crate::config::test_helpers::test_config(),
+            wallet: Keypair::new(),
+            executor: Executor::new("http://localhost:8899", None),
+            rpc_client: RpcClient::new("http://localhost:8899"),
+            holder_cache: Mutex::new(HashMap::new()),
+            buy_cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    #[test]
+    fn criteria_requires_140_holders_and_140_to_300_buys() {
+        let monitor = monitor();
+
+        assert!(!monitor.is_valid_pump_fun_criteria(&criteria(139, 200)));
+        assert!(!monitor.is_valid_pump_fun_criteria(&criteria(140, 139)));
+        assert!(!monitor.is_valid_pump_fun_criteria(&criteria(140, 301)));
+        assert!(monitor.is_valid_pump_fun_criteria(&criteria(140, 140)));
+        assert!(monitor.is_valid_pump_fun_criteria(&criteria(140, 300)));
+    }
+
+    #[test]
+    fn find_create_idempotent_accounts_reads_parsed_instruction_info() {
+        use solana_transaction_status::ParsedInstruction;
+
+        let pool_address = Pubkey::new_unique();
+        let token_address = Pubkey::new_unique();
+
+        let parsed = ParsedInstruction {
+            program: "spl-associated-token-account".to_string(),
+            program_id: ASSOCIATED_TOKEN_PROGRAM_ID.to_string(),
+            parsed: serde_json::json!({
+                "type": "createIdempotent",
+                "info": {
+                    "account": pool_address.to_string(),
+                    "mint": token_address.to_string(),
+                },
+            }),
+            stack_height: None,
+        };
+        let instructions = vec![UiInstruction::Parsed(UiParsedInstruction::Parsed(parsed))];
+
+        assert_eq!(
+            WebsocketMonitor::find_create_idempotent_accounts(&instructions),
+            Some((pool_address, token_address))
+        );
+    }
+
+    #[test]
+    fn find_create_idempotent_accounts_reads_partially_decoded_account_order() {
+        use solana_transaction_status::UiPartiallyDecodedInstruction;
+
+        let pool_address = Pubkey::new_unique();
+        let token_address = Pubkey::new_unique();
+
+        let partial = UiPartiallyDecodedInstruction {
+            program_id: ASSOCIATED_TOKEN_PROGRAM_ID.to_string(),
+            accounts: vec![
+                Pubkey::new_unique().to_string(),
+                pool_address.to_string(),
+                Pubkey::new_unique().to_string(),
+                token_address.to_string(),
+            ],
+            data: String::new(),
+            stack_height: None,
+        };
+        let instructions = vec![UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(partial))];
+
+        assert_eq!(
+            WebsocketMonitor::find_create_idempotent_accounts(&instructions),
+            Some((pool_address, token_address))
+        );
+    }
+
+    #[test]
+    fn extract_reserves_defaults_to_zero_without_transaction_meta() {
+        let (base_reserve, quote_reserve) = WebsocketMonitor::extract_reserves(
+            None,
+            &[],
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+        );
+
+        assert_eq!((base_reserve, quote_reserve), (0, 0));
+    }
 }
\ No newline at end of file
@@ -1,16 +1,46 @@
-use anyhow::Result;
-use solana_sdk::signature::Keypair;
+use anyhow::{anyhow, Context, Result};
+use solana_sdk::bs58;
+use solana_sdk::derivation_path::DerivationPath;
+use solana_sdk::signature::{keypair_from_seed_and_derivation_path, Keypair};
 use std::fs::File;
 use std::io::Read;
-use serde_json;
 
+/// Loads a wallet from `path`, auto-detecting the file's format:
+/// - a JSON byte array (the standard `solana-keygen` keypair file),
+/// - a bare base58-encoded secret key, or
+/// - a BIP39 mnemonic phrase, optionally followed by a passphrase on a
+///   second line, derived via the standard Solana path `m/44'/501'/0'/0'`.
 pub fn load_wallet(path: &str) -> Result<Keypair> {
     let mut file = File::open(path)?;
     let mut contents = String::new();
     file.read_to_string(&mut contents)?;
-    
-    let keypair_bytes: Vec<u8> = serde_json::from_str(&contents)?;
-    let keypair = Keypair::from_bytes(&keypair_bytes)?;
-    
-    Ok(keypair)
-}
\ No newline at end of file
+    let trimmed = contents.trim();
+
+    if let Ok(keypair_bytes) = serde_json::from_str::<Vec<u8>>(trimmed) {
+        return Keypair::from_bytes(&keypair_bytes).context("invalid JSON keypair bytes");
+    }
+
+    if let Ok(decoded) = bs58::decode(trimmed).into_vec() {
+        if let Ok(keypair) = Keypair::from_bytes(&decoded) {
+            return Ok(keypair);
+        }
+    }
+
+    load_wallet_from_mnemonic(trimmed)
+}
+
+/// Derives a `Keypair` from a mnemonic phrase, using the first line as the
+/// phrase and an optional second line as the BIP39 passphrase.
+fn load_wallet_from_mnemonic(contents: &str) -> Result<Keypair> {
+    let mut lines = contents.lines();
+    let phrase = lines.next().ok_or_else(|| anyhow!("empty wallet file"))?;
+    let passphrase = lines.next().unwrap_or("");
+
+    let mnemonic = bip39::Mnemonic::parse(phrase)
+        .context("not a valid JSON keypair, base58 key, or BIP39 mnemonic")?;
+    let seed = mnemonic.to_seed(passphrase);
+
+    let derivation_path = DerivationPath::new_bip44(Some(0), Some(0));
+    keypair_from_seed_and_derivation_path(&seed, Some(derivation_path))
+        .map_err(|e| anyhow!("failed to derive keypair from mnemonic: {e}"))
+}